@@ -0,0 +1,141 @@
+//! Configuration marker types used to parameterize `SerHex` and friends.
+//!
+//! Each unit struct here represents one combination of strictness (fixed-
+//! vs compact-width), prefixing (`0x`), capitalization, byte order, and
+//! permissive (decimal-accepting) parsing, governed by the
+//! [`HexConf`](trait.HexConf.html) trait.
+//! A configuration is selected via the type parameter of `SerHex`, e.g.
+//! `SerHex::<StrictPfx>`.
+
+/// Byte order used to lay out a multi-byte integer's bytes before
+/// hex-encoding, as selected by [`HexConf::endianness`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Most-significant byte first (the default).
+    Big,
+    /// Least-significant byte first.
+    Little,
+}
+
+/// Trait implemented by all configuration marker types, describing how
+/// hexadecimal values should be rendered/parsed.
+pub trait HexConf {
+    /// `true` if the encoding is fixed-width (zero-padded), `false` if
+    /// leading zeroes should be trimmed (compact).
+    fn strict() -> bool;
+    /// `true` if values should be prefixed with `0x`.
+    fn withpfx() -> bool;
+    /// `true` if hex digits should be emitted in upper-case.
+    fn withcap() -> bool;
+    /// Byte order to lay out a multi-byte integer's bytes in before
+    /// hex-encoding. Defaults to [`Endianness::Big`].
+    fn endianness() -> Endianness {
+        Endianness::Big
+    }
+    /// `true` if `from_hex_raw` should also accept a plain decimal string
+    /// (in addition to `0x`-prefixed or bare hexadecimal) when parsing an
+    /// integer. Defaults to `false`. See [`Permissive`]/[`PermissivePfx`].
+    fn permissive() -> bool {
+        false
+    }
+}
+
+macro_rules! impl_hexconf {
+    ($id:ident, $strict:expr, $pfx:expr, $cap:expr) => {
+        impl_hexconf!($id, $strict, $pfx, $cap, Endianness::Big, false);
+    };
+    ($id:ident, $strict:expr, $pfx:expr, $cap:expr, $endian:expr) => {
+        impl_hexconf!($id, $strict, $pfx, $cap, $endian, false);
+    };
+    ($id:ident, $strict:expr, $pfx:expr, $cap:expr, $endian:expr, $permissive:expr) => {
+        impl_hexconf!(
+            #[doc = "Configuration marker type for use with `SerHex` and friends."]
+            $id, $strict, $pfx, $cap, $endian, $permissive
+        );
+    };
+    (#[doc = $doc:expr] $id:ident, $strict:expr, $pfx:expr, $cap:expr, $endian:expr, $permissive:expr) => {
+        #[doc = $doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $id;
+
+        impl HexConf for $id {
+            fn strict() -> bool {
+                $strict
+            }
+            fn withpfx() -> bool {
+                $pfx
+            }
+            fn withcap() -> bool {
+                $cap
+            }
+            fn endianness() -> Endianness {
+                $endian
+            }
+            fn permissive() -> bool {
+                $permissive
+            }
+        }
+    };
+}
+
+impl_hexconf!(Strict, true, false, false);
+impl_hexconf!(StrictPfx, true, true, false);
+impl_hexconf!(StrictCap, true, false, true);
+impl_hexconf!(StrictCapPfx, true, true, true);
+impl_hexconf!(Compact, false, false, false);
+impl_hexconf!(CompactPfx, false, true, false);
+impl_hexconf!(CompactCap, false, false, true);
+impl_hexconf!(CompactCapPfx, false, true, true);
+
+impl_hexconf!(
+    #[doc = "Strict configuration, like [`Strict`], but laying out a \
+multi-byte integer's bytes least-significant-first (little-endian) \
+before hex-encoding."]
+    StrictLe, true, false, false, Endianness::Little, false
+);
+impl_hexconf!(
+    #[doc = "Like [`StrictLe`], but serializes with a `0x` prefix."]
+    StrictLePfx, true, true, false, Endianness::Little, false
+);
+impl_hexconf!(
+    #[doc = "Like [`StrictLe`], but serializes with upper-case hex digits."]
+    StrictCapLe, true, false, true, Endianness::Little, false
+);
+impl_hexconf!(
+    #[doc = "Like [`StrictLe`], but serializes with upper-case hex digits and \
+a `0x` prefix."]
+    StrictCapLePfx, true, true, true, Endianness::Little, false
+);
+
+impl_hexconf!(
+    #[doc = "Compact configuration whose `from_hex_raw` additionally accepts plain \
+decimal strings (e.g. `\"42\"`) alongside `0x`-prefixed and bare \
+hexadecimal, for ingesting JSON from producers that don't agree on a \
+single numeric format. A bare (non-`0x`-prefixed) string consisting \
+entirely of ascii decimal digits is always treated as decimal, even \
+though it would also be valid hexadecimal (e.g. `\"42\"` parses as \
+decimal `42`, not hexadecimal `0x42`); prefix with `0x` to force \
+hexadecimal, or include an `a`-`f` digit to disambiguate (e.g. \
+`\"2a\"`). Serialization is unaffected and stays canonical compact hex \
+(no `0x` prefix)."]
+    Permissive, false, false, false, Endianness::Big, true
+);
+impl_hexconf!(
+    #[doc = "Like [`Permissive`], but serializes with a `0x` prefix."]
+    PermissivePfx, false, true, false, Endianness::Big, true
+);
+
+/// Marker trait implemented only by the strict (fixed-width) configuration
+/// types. Used to restrict the generic array impls of `SerHex` to strict
+/// configurations, since arrays of compact (variable-width) elements would
+/// be ambiguous to split back into individual elements.
+pub trait StrictConf: HexConf {}
+
+impl StrictConf for Strict {}
+impl StrictConf for StrictPfx {}
+impl StrictConf for StrictCap {}
+impl StrictConf for StrictCapPfx {}
+impl StrictConf for StrictLe {}
+impl StrictConf for StrictLePfx {}
+impl StrictConf for StrictCapLe {}
+impl StrictConf for StrictCapLePfx {}
@@ -24,13 +24,38 @@
 //! representations, prefixing, and capitalizing (e.g.; `Compact`,
 //! `StrictCapPfx`, etc...).
 //!
-//! This crate provides implementations of `SerHex` for all unsigned integer types,
-//! as well as generic impls for arrays of types which implement `SerHex`.  The generic
-//! impls apply only to strict variants of the trait, and only for arrays of length 1
-//! through 64 (no impl is provided for arrays of length 0 since there isn't really
-//! a reasonable way to represent a zero-sized value in hex).
+//! This crate provides implementations of `SerHex` for all integer types
+//! (signed and unsigned, `i8`/`u8` through `i128`/`u128`), as well as a generic
+//! impl for arrays `[T; N]` of any length, where `T` implements `SerHex`.  The
+//! generic impl applies only to strict variants of the trait. Arrays of
+//! length 0 trivially encode to an empty (or bare-prefixed) value and decode
+//! from an empty buffer.
 //!
+//! Signed integers use fixed-width two's-complement hex under strict
+//! configurations, and the Ethereum-style `QUANTITY` convention under
+//! compact configurations: a leading `-` for negative values followed by a
+//! trimmed hexadecimal magnitude (e.g.; `-0x2a`), with `0` rendered as `0`.
 //!
+//! With the `faster_hex` feature enabled, the bulk of the hex conversion
+//! work (decoding `SerHexSeq` buffers, encoding/decoding strict integers) is
+//! delegated to [`faster_hex`](https://crates.io/crates/faster_hex)'s
+//! vectorized implementation, which is substantially faster for
+//! multi-kilobyte inputs. Arrays decode element-by-element instead (so each
+//! element's byte order honors its configured endianness), but still benefit
+//! per-element for large `HEX_SIZE`s. The scalar implementation remains the
+//! default so `no_std`/minimal builds are unaffected.
+//!
+//! Strict configurations default to laying out a multi-byte integer's bytes
+//! most-significant-first before hex-encoding. The `StrictLe`, `StrictLePfx`,
+//! `StrictCapLe`, and `StrictCapLePfx` configurations select least-significant-
+//! first (little-endian) byte order instead, via [`HexConf::endianness`].
+//!
+//! The `Permissive`/`PermissivePfx` configurations relax integer parsing to
+//! additionally accept plain decimal strings (e.g. `"42"`) alongside
+//! `0x`-prefixed and bare hexadecimal, which is useful when ingesting JSON
+//! from producers that don't agree on a single numeric format up front.
+//! Serialization under these configurations is unaffected, staying canonical
+//! compact hex.
 //!
 #![warn(missing_docs)]
 
@@ -46,17 +71,35 @@ pub use config::*;
 pub use types::{Error, ParseHexError};
 
 use core::{iter::FromIterator, marker::PhantomData};
-use std::{error, fmt, io};
+use std::{convert::TryInto, error, fmt, io};
 
-use serde::{de::Visitor, Deserializer, Serializer};
+use serde::{de::Visitor, Deserializer, Serialize, Serializer};
 use smallvec::SmallVec;
 
+/// Thin `Serialize` wrapper which always writes via `serialize_bytes`,
+/// regardless of the underlying value's own `Serialize` impl. Used to push
+/// raw bytes through `Serializer::serialize_some`.
+struct RawBytes<'a>(&'a [u8]);
+
+impl<'a> Serialize for RawBytes<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
 /// Trait specifying custom serialization and deserialization logic from a
 /// hexadecimal string to some arbitrary type.  This trait can be used to apply
 /// custom parsing when using serde's `#[derive(Serialize,Deserialize)]`
 /// flag.  Just add `#[serde(with = "SerHex")]` above any fields which implement
 /// this trait.  Simplistic default implimentations for the the `serialize` and
 /// `deserialize` methods are provided based on `into_hex_raw` and `from_hex_raw` respectively.
+// `into_hex_raw`/`into_bytes_raw` take `&self` rather than `self` to match
+// `into_hex`'s established signature and avoid forcing a move of sized
+// arrays/integers on every write.
+#[allow(clippy::wrong_self_convention)]
 pub trait SerHex<C>: Sized
 where
     C: HexConf,
@@ -70,6 +113,12 @@ where
     /// for the `serde-hex` error type).
     type Error: error::Error;
 
+    /// Size, in bytes, of `Self`'s raw (decoded) representation; its strict
+    /// (fixed-width) hex encoding holds `2 * HEX_SIZE` digits. Used by the
+    /// generic array impls to compute expected buffer sizes at compile time
+    /// without having to decode an element first.
+    const HEX_SIZE: usize;
+
     /// Attept to convert `self` to hexadecimal, writing the resultant bytes to some buffer.
     fn into_hex_raw<D>(&self, dst: D) -> Result<(), Self::Error>
     where
@@ -80,6 +129,19 @@ where
     where
         S: AsRef<[u8]>;
 
+    /// Write `self`'s raw (big-endian) byte representation to `dst`, independent
+    /// of any particular hex configuration. Used to serialize into
+    /// non-human-readable formats (e.g.; `bincode`) without paying for a
+    /// hex-string round-trip.
+    fn into_bytes_raw<D>(&self, dst: D) -> Result<(), Self::Error>
+    where
+        D: io::Write;
+
+    /// Parse `self` from a buffer of raw bytes, the inverse of `into_bytes_raw`.
+    fn from_bytes_raw<S>(src: S) -> Result<Self, Self::Error>
+    where
+        S: AsRef<[u8]>;
+
     /// Attempt to convert `self` into a hexadecimal string representation.
     fn into_hex(&self) -> Result<String, Self::Error> {
         let mut dst: Vec<u8> = Vec::with_capacity(32);
@@ -100,27 +162,43 @@ where
     /// *NOTE*: The default implementation attempts to avoid heap-allocation with a
     /// [`SmallVec`](https://docs.rs/smallvec/) of size `[u8;64]`. This default will
     /// prevent heap-alloc for non-prefixed serializations of `[u8;32]` or smaller.
+    ///
+    /// When serializing to a non-human-readable format (per
+    /// [`Serializer::is_human_readable`]), `self` is written as its raw bytes
+    /// instead, so binary formats like `bincode` don't pay for a hex round-trip.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         use serde::ser::Error;
         let mut dst = SmallVec::<[u8; 64]>::new();
-        self.into_hex_raw(&mut dst).map_err(S::Error::custom)?;
-        // if `dst` is not valid UTF-8 bytes, the underlying implementation
-        // is very broken, and you should be ashamed of yourelf.
-        debug_assert!(::std::str::from_utf8(dst.as_ref()).is_ok());
-        let s = unsafe { ::std::str::from_utf8_unchecked(dst.as_ref()) };
-        serializer.serialize_str(s)
+        if serializer.is_human_readable() {
+            self.into_hex_raw(&mut dst).map_err(S::Error::custom)?;
+            // if `dst` is not valid UTF-8 bytes, the underlying implementation
+            // is very broken, and you should be ashamed of yourelf.
+            debug_assert!(::std::str::from_utf8(dst.as_ref()).is_ok());
+            let s = unsafe { ::std::str::from_utf8_unchecked(dst.as_ref()) };
+            serializer.serialize_str(s)
+        } else {
+            self.into_bytes_raw(&mut dst).map_err(S::Error::custom)?;
+            serializer.serialize_bytes(dst.as_ref())
+        }
     }
 
     /// Attempt to deserialize a hexadecimal string into an instance of `Self`.
+    ///
+    /// When deserializing from a non-human-readable format (per
+    /// [`Deserializer::is_human_readable`]), raw bytes are expected instead
+    /// of a hex string.
     fn deserialize<'de, D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let rslt = deserializer.deserialize_any(HexBytesVisitor::default())?;
-        Ok(rslt)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(HexBytesVisitor::default())
+        } else {
+            deserializer.deserialize_bytes(BinBytesVisitor::default())
+        }
     }
 }
 
@@ -169,6 +247,55 @@ where
     }
 }
 
+struct BinBytesVisitor<S, C> {
+    _phantom: PhantomData<(S, C)>,
+}
+
+impl<S, C> Default for BinBytesVisitor<S, C> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'de, S, C> Visitor<'de> for BinBytesVisitor<S, C>
+where
+    S: SerHex<C>,
+    C: HexConf,
+{
+    type Value = S;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("raw bytes")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        S::from_bytes_raw(v).map_err(E::custom)
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        S::from_bytes_raw(v).map_err(E::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut buf = Vec::with_capacity(S::HEX_SIZE);
+        while let Some(byte) = seq.next_element::<u8>()? {
+            buf.push(byte);
+        }
+        S::from_bytes_raw(buf).map_err(serde::de::Error::custom)
+    }
+}
+
 /// Variant of `SerHex` for serializing/deserializing `Option` types.
 ///
 /// Any type `T` which implements `SerHex<C>` implements `SerHexOpt<C>`
@@ -198,33 +325,48 @@ where
     C: HexConf,
 {
     /// Same as `SerHex::serialize`, except for `Option<Self>` instead of `Self`.
+    ///
+    /// When serializing to a non-human-readable format, `self` is written as
+    /// its raw bytes instead of a hex string (see `SerHex::serialize`).
     fn serialize<S>(option: &Option<Self>, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         use serde::ser::Error;
-        if let Some(ref src) = *option {
+        if serializer.is_human_readable() {
+            if let Some(ref src) = *option {
+                let mut dst = SmallVec::<[u8; 64]>::new();
+                Self::into_hex_raw(src, &mut dst).map_err(S::Error::custom)?;
+                // if `dst` is not valid UTF-8 bytes, the underlying implementation
+                // is very broken, and you should be ashamed of yourelf.
+                debug_assert!(::std::str::from_utf8(dst.as_ref()).is_ok());
+                let s = unsafe { ::std::str::from_utf8_unchecked(dst.as_ref()) };
+                serializer.serialize_some(s)
+            } else {
+                serializer.serialize_none()
+            }
+        } else if let Some(ref src) = *option {
             let mut dst = SmallVec::<[u8; 64]>::new();
-            Self::into_hex_raw(src, &mut dst).map_err(S::Error::custom)?;
-            // if `dst` is not valid UTF-8 bytes, the underlying implementation
-            // is very broken, and you should be ashamed of yourelf.
-            debug_assert!(::std::str::from_utf8(dst.as_ref()).is_ok());
-            let s = unsafe { ::std::str::from_utf8_unchecked(dst.as_ref()) };
-            //serializer.serialize_str(s)
-            serializer.serialize_some(s)
+            Self::into_bytes_raw(src, &mut dst).map_err(S::Error::custom)?;
+            serializer.serialize_some(&RawBytes(dst.as_ref()))
         } else {
             serializer.serialize_none()
         }
     }
 
     /// Same as `SerHex::deserialize`, except for `Option<Self>` instead of `Self`.
+    ///
+    /// When deserializing from a non-human-readable format, raw bytes are
+    /// expected instead of a hex string (see `SerHex::deserialize`).
     fn deserialize<'de, D>(deserializer: D) -> Result<Option<Self>, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let option = deserializer.deserialize_any(OptHexBytesVisitor::default())?;
-
-        Ok(option)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(OptHexBytesVisitor::default())
+        } else {
+            deserializer.deserialize_option(OptBinBytesVisitor::default())
+        }
     }
 }
 
@@ -309,6 +451,53 @@ where
     }
 }
 
+struct OptBinBytesVisitor<S, C> {
+    _phantom: PhantomData<(S, C)>,
+}
+
+impl<S, C> Default for OptBinBytesVisitor<S, C> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'de, S, C> Visitor<'de> for OptBinBytesVisitor<S, C>
+where
+    S: SerHexOpt<C>,
+    C: HexConf,
+{
+    type Value = Option<S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("optional raw bytes")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let result = deserializer.deserialize_bytes(BinBytesVisitor::<S, C>::default())?;
+
+        Ok(Some(result))
+    }
+}
+
 /// Variant of `SerHex` for serializing/deserializing sequence types as
 /// contiguous hexadecimal strings.
 ///
@@ -338,6 +527,10 @@ where
     fn size() -> usize;
 
     /// Same as `SerHex::serialize`, but for sequences of `Self`.
+    ///
+    /// When serializing to a non-human-readable format, the sequence is
+    /// written as the concatenation of each element's raw bytes instead of a
+    /// hex string (see `SerHex::serialize`).
     fn serialize<'a, S, T>(sequence: T, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -345,31 +538,48 @@ where
         Self: 'a,
     {
         use serde::ser::Error;
-        let mut dst = SmallVec::<[u8; 128]>::new();
-        if <C as HexConf>::withpfx() {
-            dst.extend_from_slice(b"0x");
-        }
-        if <C as HexConf>::withcap() {
-            for elem in sequence.into_iter() {
-                <Self as SerHex<StrictCap>>::into_hex_raw(elem, &mut dst)
-                    .map_err(S::Error::custom)?;
+        if serializer.is_human_readable() {
+            let mut dst = SmallVec::<[u8; 128]>::new();
+            if <C as HexConf>::withpfx() {
+                dst.extend_from_slice(b"0x");
             }
+            if <C as HexConf>::withcap() {
+                for elem in sequence.into_iter() {
+                    <Self as SerHex<StrictCap>>::into_hex_raw(elem, &mut dst)
+                        .map_err(S::Error::custom)?;
+                }
+            } else {
+                for elem in sequence.into_iter() {
+                    <Self as SerHex<Strict>>::into_hex_raw(elem, &mut dst)
+                        .map_err(S::Error::custom)?;
+                }
+            }
+            let s = unsafe { ::std::str::from_utf8_unchecked(dst.as_ref()) };
+            serializer.serialize_str(s)
         } else {
+            let mut dst = SmallVec::<[u8; 128]>::new();
             for elem in sequence.into_iter() {
-                <Self as SerHex<Strict>>::into_hex_raw(elem, &mut dst).map_err(S::Error::custom)?;
+                <Self as SerHex<Strict>>::into_bytes_raw(elem, &mut dst)
+                    .map_err(S::Error::custom)?;
             }
+            serializer.serialize_bytes(dst.as_ref())
         }
-        let s = unsafe { ::std::str::from_utf8_unchecked(dst.as_ref()) };
-        serializer.serialize_str(s)
     }
 
     /// Same as `SerHex::deserialize`, but for sequences of `Self`.
+    ///
+    /// When deserializing from a non-human-readable format, raw bytes are
+    /// expected instead of a hex string (see `SerHex::deserialize`).
     fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
     where
         D: Deserializer<'de>,
         T: FromIterator<Self>,
     {
-        deserializer.deserialize_bytes(SeqHexBytesVisitor::<Self, C, T>::default())
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_bytes(SeqHexBytesVisitor::<Self, C, T>::default())
+        } else {
+            deserializer.deserialize_bytes(SeqBinBytesVisitor::<Self, T>::default())
+        }
     }
 }
 
@@ -432,20 +642,17 @@ where
     E: serde::de::Error,
     T: FromIterator<S>,
 {
-    let src = if raw.starts_with(b"0x") {
-        &raw[2..]
-    } else {
-        &raw
-    };
+    let src = utils::strip_prefix(raw);
 
     let hexsize = size_hint * 2;
-    if src.len() % hexsize == 0 && hexsize != 0 && !src.is_empty() {
-        // if src.len() % hexsize == 0 {
-        let mut buff = Vec::with_capacity(src.len() / hexsize);
-        // if chunk size is 0 then chunks() will panic!
-        for chunk in src.chunks(hexsize) {
-            let elem = S::from_hex_raw(chunk).map_err(E::custom)?;
-            buff.push(elem);
+    if hexsize != 0 && !src.is_empty() && src.len().is_multiple_of(hexsize) {
+        // decode the whole blob in one pass (picking up the `faster_hex`
+        // SIMD backend when enabled) rather than re-parsing a hex prefix
+        // per element, then split the raw bytes into per-element chunks.
+        let bytes = utils::parse_strict_hex(src, src.len() / 2).map_err(E::custom)?;
+        let mut buff = Vec::with_capacity(bytes.len() / size_hint);
+        for chunk in bytes.chunks(size_hint) {
+            buff.push(S::from_bytes_raw(chunk).map_err(E::custom)?);
         }
         Ok(buff.into_iter().collect())
     } else {
@@ -453,16 +660,189 @@ where
     }
 }
 
+struct SeqBinBytesVisitor<S, T> {
+    _phantom: PhantomData<(S, T)>,
+}
+
+impl<S, T> Default for SeqBinBytesVisitor<S, T> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'de, S, T> Visitor<'de> for SeqBinBytesVisitor<S, T>
+where
+    S: SerHex<Strict>,
+    T: FromIterator<S>,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("raw bytes")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        seq_from_raw_bytes(v)
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        seq_from_raw_bytes(v)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut buf = Vec::new();
+        while let Some(byte) = seq.next_element::<u8>()? {
+            buf.push(byte);
+        }
+        seq_from_raw_bytes(&buf)
+    }
+}
+
+fn seq_from_raw_bytes<S, E, T>(raw: &[u8]) -> Result<T, E>
+where
+    S: SerHex<Strict>,
+    E: serde::de::Error,
+    T: FromIterator<S>,
+{
+    let size = S::HEX_SIZE;
+    if size != 0 && raw.len().is_multiple_of(size) {
+        let mut buff = Vec::with_capacity(raw.len() / size);
+        for chunk in raw.chunks(size) {
+            buff.push(S::from_bytes_raw(chunk).map_err(E::custom)?);
+        }
+        Ok(buff.into_iter().collect())
+    } else {
+        Err(E::custom("bad raw byte sequence size"))
+    }
+}
+
 impl_serhex_uint!(u8, 1);
 impl_serhex_uint!(u16, 2);
 impl_serhex_uint!(u32, 4);
 impl_serhex_uint!(u64, 8);
+impl_serhex_uint!(u128, 16);
+
+impl_serhex_int!(i8, u8, 1);
+impl_serhex_int!(i16, u16, 2);
+impl_serhex_int!(i32, u32, 4);
+impl_serhex_int!(i64, u64, 8);
+impl_serhex_int!(i128, u128, 16);
+
+// Blanket impl of the strict variants of `SerHex` for arrays of any length
+// `N`, where `T` implements the strict variants of `SerHex` as well. `N ==
+// 0` isn't special-cased: it trivially encodes to an empty (or
+// bare-prefixed) value and decodes from an empty buffer.
+impl<C, T, const N: usize> SerHex<C> for [T; N]
+where
+    C: StrictConf,
+    T: SerHex<C>,
+    T::Error: From<Error>,
+{
+    type Error = T::Error;
+
+    const HEX_SIZE: usize = T::HEX_SIZE * N;
 
-// implement strict variants of `SerHex` for arrays of `T` with
-// lengths of 1 through 64 (where `T` implements the strict variants
-// of `SerHex` as well).
-impl_serhex_strict_array!(
-    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
-    27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
-    51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
-);
+    fn into_hex_raw<D>(&self, mut dst: D) -> Result<(), Self::Error>
+    where
+        D: io::Write,
+    {
+        if <C as HexConf>::withpfx() {
+            dst.write_all(b"0x")
+                .map_err(|e| T::Error::from(Error::from(e)))?;
+            // each element writes its own `0x` prefix under `C`; buffer one
+            // element at a time and drop its prefix so only the one written
+            // above survives.
+            let mut tmp = Vec::with_capacity(T::HEX_SIZE * 2 + 2);
+            for elem in self.iter() {
+                tmp.clear();
+                elem.into_hex_raw(&mut tmp)?;
+                dst.write_all(&tmp[2..])
+                    .map_err(|e| T::Error::from(Error::from(e)))?;
+            }
+        } else {
+            for elem in self.iter() {
+                elem.into_hex_raw(&mut dst)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn from_hex_raw<S>(src: S) -> Result<Self, Self::Error>
+    where
+        S: AsRef<[u8]>,
+    {
+        let raw = utils::strip_prefix(src.as_ref());
+        let elem_digits = T::HEX_SIZE * 2;
+        if !raw.len().is_multiple_of(N) {
+            return Err(T::Error::from(Error::from(ParseHexError::BadSize {
+                expect: T::HEX_SIZE * N,
+                got: raw.len() / 2,
+            })));
+        }
+        let chunk_size = raw.len().checked_div(N).unwrap_or(0);
+        let mut out = Vec::with_capacity(N);
+        for chunk in raw.chunks(chunk_size.max(1)).take(N) {
+            if chunk.len() != elem_digits {
+                return Err(T::Error::from(Error::from(ParseHexError::BadSize {
+                    expect: elem_digits,
+                    got: chunk.len(),
+                })));
+            }
+            // decode each element with `T::from_hex_raw` (rather than
+            // splitting a bulk-decoded byte buffer) so per-element byte
+            // order honors `C`'s configured endianness.
+            out.push(T::from_hex_raw(chunk)?);
+        }
+        if out.len() != N {
+            return Err(T::Error::from(Error::from(ParseHexError::BadSize {
+                expect: T::HEX_SIZE * N,
+                got: raw.len() / 2,
+            })));
+        }
+        Ok(out
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("size checked above")))
+    }
+
+    fn into_bytes_raw<D>(&self, mut dst: D) -> Result<(), Self::Error>
+    where
+        D: io::Write,
+    {
+        for elem in self.iter() {
+            elem.into_bytes_raw(&mut dst)?;
+        }
+        Ok(())
+    }
+
+    fn from_bytes_raw<S>(src: S) -> Result<Self, Self::Error>
+    where
+        S: AsRef<[u8]>,
+    {
+        let raw = src.as_ref();
+        let elem_size = T::HEX_SIZE;
+        if raw.len() != elem_size * N {
+            return Err(T::Error::from(Error::from(ParseHexError::BadSize {
+                expect: T::HEX_SIZE * N,
+                got: raw.len(),
+            })));
+        }
+        let mut out = Vec::with_capacity(N);
+        for chunk in raw.chunks(elem_size.max(1)) {
+            out.push(T::from_bytes_raw(chunk)?);
+        }
+        Ok(out
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("size checked above")))
+    }
+}
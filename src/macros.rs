@@ -0,0 +1,258 @@
+//! Macros which generate the built-in `SerHex` implementations.
+
+/// Implements `SerHex<C>` (for all `C: HexConf`) on an unsigned integer
+/// type.  Strict configurations produce fixed-width hex, in the byte order
+/// selected by `HexConf::endianness`; compact configurations trim leading zero digits, rendering zero as
+/// `0`/`0x0`.  Also implements `SerHexSeq<C>` (for all `C: HexConf`), since
+/// an unsigned integer's strict-variant impls are always fixed-width.
+#[macro_export]
+macro_rules! impl_serhex_uint {
+    ($t:ty, $size:expr) => {
+        impl<C> $crate::SerHex<C> for $t
+        where
+            C: $crate::HexConf,
+        {
+            type Error = $crate::Error;
+
+            const HEX_SIZE: usize = $size;
+
+            fn into_hex_raw<D>(&self, mut dst: D) -> Result<(), Self::Error>
+            where
+                D: ::std::io::Write,
+            {
+                if <C as $crate::HexConf>::withpfx() {
+                    dst.write_all(b"0x")?;
+                }
+                let bytes = match <C as $crate::HexConf>::endianness() {
+                    $crate::config::Endianness::Big => self.to_be_bytes(),
+                    $crate::config::Endianness::Little => self.to_le_bytes(),
+                };
+                if <C as $crate::HexConf>::strict() {
+                    $crate::utils::write_strict_hex(
+                        &bytes,
+                        <C as $crate::HexConf>::withcap(),
+                        &mut dst,
+                    )?;
+                } else {
+                    $crate::utils::write_compact_hex(
+                        &bytes,
+                        <C as $crate::HexConf>::withcap(),
+                        &mut dst,
+                    )?;
+                }
+                Ok(())
+            }
+
+            fn from_hex_raw<S>(src: S) -> Result<Self, Self::Error>
+            where
+                S: AsRef<[u8]>,
+            {
+                let raw = src.as_ref();
+                if <C as $crate::HexConf>::strict() {
+                    let digits = $crate::utils::parse_strict_hex(raw, $size)?;
+                    let mut buf = [0u8; $size];
+                    buf.copy_from_slice(&digits);
+                    return Ok(match <C as $crate::HexConf>::endianness() {
+                        $crate::config::Endianness::Big => <$t>::from_be_bytes(buf),
+                        $crate::config::Endianness::Little => <$t>::from_le_bytes(buf),
+                    });
+                }
+                if <C as $crate::HexConf>::permissive() && $crate::utils::is_bare_decimal(raw) {
+                    let s = ::std::str::from_utf8(raw)
+                        .map_err(|_| $crate::types::ParseHexError::BadUtf8)?;
+                    return s.parse::<$t>().map_err(|_| {
+                        $crate::types::ParseHexError::Custom(format!(
+                            "invalid decimal value `{}`",
+                            s
+                        ))
+                        .into()
+                    });
+                }
+                let digits = $crate::utils::parse_compact_hex(raw)?;
+                if digits.len() > $size {
+                    return Err($crate::types::ParseHexError::BadSize {
+                        expect: $size,
+                        got: digits.len(),
+                    }
+                    .into());
+                }
+                let mut buf = [0u8; $size];
+                buf[$size - digits.len()..].copy_from_slice(&digits);
+                Ok(<$t>::from_be_bytes(buf))
+            }
+
+            fn into_bytes_raw<D>(&self, mut dst: D) -> Result<(), Self::Error>
+            where
+                D: ::std::io::Write,
+            {
+                dst.write_all(&self.to_be_bytes())?;
+                Ok(())
+            }
+
+            fn from_bytes_raw<S>(src: S) -> Result<Self, Self::Error>
+            where
+                S: AsRef<[u8]>,
+            {
+                let raw = src.as_ref();
+                if raw.len() != $size {
+                    return Err($crate::types::ParseHexError::BadSize {
+                        expect: $size,
+                        got: raw.len(),
+                    }
+                    .into());
+                }
+                let mut buf = [0u8; $size];
+                buf.copy_from_slice(raw);
+                Ok(<$t>::from_be_bytes(buf))
+            }
+        }
+
+        impl<C> $crate::SerHexSeq<C> for $t
+        where
+            C: $crate::HexConf,
+        {
+            fn size() -> usize {
+                $size
+            }
+        }
+    };
+}
+
+/// Implements `SerHex<C>` (for all `C: HexConf`) on a signed integer type.
+///
+/// Strict configurations use fixed-width two's-complement hex of the
+/// type's byte size, so round-tripping is lossless. Compact configurations
+/// use the Ethereum-style `QUANTITY` convention: a leading `-` for negative
+/// values, followed by a `0x`-prefixed (if configured) trimmed hex encoding
+/// of the value's magnitude, with zero rendered as `0`/`0x0`.
+#[macro_export]
+macro_rules! impl_serhex_int {
+    ($t:ty, $u:ty, $size:expr) => {
+        impl<C> $crate::SerHex<C> for $t
+        where
+            C: $crate::HexConf,
+        {
+            type Error = $crate::Error;
+
+            const HEX_SIZE: usize = $size;
+
+            fn into_hex_raw<D>(&self, mut dst: D) -> Result<(), Self::Error>
+            where
+                D: ::std::io::Write,
+            {
+                if <C as $crate::HexConf>::strict() {
+                    if <C as $crate::HexConf>::withpfx() {
+                        dst.write_all(b"0x")?;
+                    }
+                    let bytes = match <C as $crate::HexConf>::endianness() {
+                        $crate::config::Endianness::Big => self.to_be_bytes(),
+                        $crate::config::Endianness::Little => self.to_le_bytes(),
+                    };
+                    $crate::utils::write_strict_hex(
+                        &bytes,
+                        <C as $crate::HexConf>::withcap(),
+                        &mut dst,
+                    )?;
+                } else {
+                    if *self < 0 {
+                        dst.write_all(b"-")?;
+                    }
+                    if <C as $crate::HexConf>::withpfx() {
+                        dst.write_all(b"0x")?;
+                    }
+                    let magnitude = self.unsigned_abs();
+                    $crate::utils::write_compact_hex(
+                        &magnitude.to_be_bytes(),
+                        <C as $crate::HexConf>::withcap(),
+                        &mut dst,
+                    )?;
+                }
+                Ok(())
+            }
+
+            fn from_hex_raw<S>(src: S) -> Result<Self, Self::Error>
+            where
+                S: AsRef<[u8]>,
+            {
+                let raw = src.as_ref();
+                if <C as $crate::HexConf>::strict() {
+                    let digits = $crate::utils::parse_strict_hex(raw, $size)?;
+                    let mut buf = [0u8; $size];
+                    buf.copy_from_slice(&digits);
+                    Ok(match <C as $crate::HexConf>::endianness() {
+                        $crate::config::Endianness::Big => <$t>::from_be_bytes(buf),
+                        $crate::config::Endianness::Little => <$t>::from_le_bytes(buf),
+                    })
+                } else {
+                    let (neg, rest) = match raw.split_first() {
+                        Some((b'-', rest)) => (true, rest),
+                        _ => (false, raw),
+                    };
+                    if <C as $crate::HexConf>::permissive() && $crate::utils::is_bare_decimal(rest)
+                    {
+                        let s = ::std::str::from_utf8(raw)
+                            .map_err(|_| $crate::types::ParseHexError::BadUtf8)?;
+                        return s.parse::<$t>().map_err(|_| {
+                            $crate::types::ParseHexError::Custom(format!(
+                                "invalid decimal value `{}`",
+                                s
+                            ))
+                            .into()
+                        });
+                    }
+                    let digits = $crate::utils::parse_compact_hex(rest)?;
+                    if digits.len() > $size {
+                        return Err($crate::types::ParseHexError::BadSize {
+                            expect: $size,
+                            got: digits.len(),
+                        }
+                        .into());
+                    }
+                    let mut buf = [0u8; $size];
+                    buf[$size - digits.len()..].copy_from_slice(&digits);
+                    let magnitude = <$u>::from_be_bytes(buf);
+                    let limit = if neg {
+                        (<$t>::MIN).unsigned_abs()
+                    } else {
+                        <$t>::MAX as $u
+                    };
+                    if magnitude > limit {
+                        return Err($crate::types::ParseHexError::Custom(format!(
+                            "`{}` out of range for `{}`",
+                            magnitude,
+                            stringify!($t)
+                        ))
+                        .into());
+                    }
+                    let value = magnitude as $t;
+                    Ok(if neg { value.wrapping_neg() } else { value })
+                }
+            }
+
+            fn into_bytes_raw<D>(&self, mut dst: D) -> Result<(), Self::Error>
+            where
+                D: ::std::io::Write,
+            {
+                dst.write_all(&self.to_be_bytes())?;
+                Ok(())
+            }
+
+            fn from_bytes_raw<S>(src: S) -> Result<Self, Self::Error>
+            where
+                S: AsRef<[u8]>,
+            {
+                let raw = src.as_ref();
+                if raw.len() != $size {
+                    return Err($crate::types::ParseHexError::BadSize {
+                        expect: $size,
+                        got: raw.len(),
+                    }
+                    .into());
+                }
+                let mut buf = [0u8; $size];
+                buf.copy_from_slice(raw);
+                Ok(<$t>::from_be_bytes(buf))
+            }
+        }
+    };
+}
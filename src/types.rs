@@ -0,0 +1,83 @@
+//! Error types produced by `serde-hex`'s built-in `SerHex` implementations.
+
+use std::{error, fmt, io, str};
+
+/// Error type used by the built-in `SerHex` implementations.
+#[derive(Debug)]
+pub enum Error {
+    /// A problem was encountered while parsing a hexadecimal string.
+    Parse(ParseHexError),
+    /// An I/O error occurred while writing hexadecimal output.
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::Parse(ref e) => write!(f, "{}", e),
+            Error::Io(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Parse(ref e) => Some(e),
+            Error::Io(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseHexError> for Error {
+    fn from(err: ParseHexError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Error produced while parsing a hexadecimal string into some value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseHexError {
+    /// A byte outside the ascii hexadecimal alphabet was encountered.
+    BadDigit(u8),
+    /// The source was not valid utf-8.
+    BadUtf8,
+    /// The buffer did not contain the expected number of bytes.
+    BadSize {
+        /// the number of bytes expected (hex digits, when it reports one
+        /// array element's slice of the input having the wrong width)
+        expect: usize,
+        /// the number of bytes actually present (hex digits, in the same
+        /// per-element case)
+        got: usize,
+    },
+    /// A generic/custom parse error with a message.
+    Custom(String),
+}
+
+impl fmt::Display for ParseHexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParseHexError::BadDigit(b) => write!(f, "invalid hex digit `{}`", b as char),
+            ParseHexError::BadUtf8 => write!(f, "invalid utf-8 in hexadecimal source"),
+            ParseHexError::BadSize { expect, got } => {
+                write!(f, "expected buff size `{}` got `{}`", expect, got)
+            }
+            ParseHexError::Custom(ref s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl error::Error for ParseHexError {}
+
+impl From<str::Utf8Error> for ParseHexError {
+    fn from(_: str::Utf8Error) -> Self {
+        ParseHexError::BadUtf8
+    }
+}
@@ -0,0 +1,172 @@
+//! Low-level helpers for converting bytes to/from hexadecimal ascii, shared
+//! by the `SerHex` implementations generated in [`macros`](../macros/index.html).
+
+use crate::types::ParseHexError;
+use std::io;
+
+const HEX_CHARS_LOWER: &[u8; 16] = b"0123456789abcdef";
+const HEX_CHARS_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Write a single hexadecimal nibble (`0..=15`) as an ascii character.
+pub fn write_nibble<D: io::Write>(nibble: u8, cap: bool, mut dst: D) -> io::Result<()> {
+    let table = if cap {
+        HEX_CHARS_UPPER
+    } else {
+        HEX_CHARS_LOWER
+    };
+    dst.write_all(&[table[(nibble & 0x0f) as usize]])
+}
+
+/// Write the two-digit hexadecimal representation of `byte` to `dst`, using
+/// upper-case digits if `cap` is `true`.
+pub fn byte_to_hex<D: io::Write>(byte: u8, cap: bool, mut dst: D) -> io::Result<()> {
+    let table = if cap {
+        HEX_CHARS_UPPER
+    } else {
+        HEX_CHARS_LOWER
+    };
+    dst.write_all(&[table[(byte >> 4) as usize], table[(byte & 0x0f) as usize]])
+}
+
+/// Parse a single ascii hex digit into its nibble value.
+pub fn hex_digit_to_nibble(digit: u8) -> Result<u8, ParseHexError> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(ParseHexError::BadDigit(digit)),
+    }
+}
+
+/// `true` if `src` is non-empty and consists entirely of ascii decimal
+/// digits, i.e. it should be parsed as a plain decimal number rather than
+/// hexadecimal. Used by [`HexConf::permissive`](crate::HexConf::permissive)
+/// configurations to distinguish `"42"` (decimal) from `"0x2a"` (hex).
+pub fn is_bare_decimal(src: &[u8]) -> bool {
+    !src.is_empty() && src.iter().all(u8::is_ascii_digit)
+}
+
+/// Strip a leading `0x`/`0X` prefix from `src`, if present.
+pub fn strip_prefix(src: &[u8]) -> &[u8] {
+    if src.len() >= 2 && src[0] == b'0' && (src[1] == b'x' || src[1] == b'X') {
+        &src[2..]
+    } else {
+        src
+    }
+}
+
+/// Write the fixed-width ("strict") hexadecimal representation of `bytes`
+/// (in the order given) to `dst`.
+///
+/// With the `faster_hex` feature enabled, the bulk of the conversion is
+/// delegated to `faster_hex`'s vectorized encoder; capitalization is handled
+/// by picking its upper/lower-case entry point, so no scalar fallback is
+/// needed on this path.
+#[cfg(feature = "faster_hex")]
+pub fn write_strict_hex<D: io::Write>(bytes: &[u8], cap: bool, mut dst: D) -> io::Result<()> {
+    let mut buf = vec![0u8; bytes.len() * 2];
+    let encoded = if cap {
+        faster_hex::hex_encode_upper(bytes, &mut buf)
+    } else {
+        faster_hex::hex_encode(bytes, &mut buf)
+    };
+    let encoded = encoded.expect("buf is sized to exactly fit the encoded output");
+    dst.write_all(encoded.as_bytes())
+}
+
+/// Write the fixed-width ("strict") hexadecimal representation of `bytes`
+/// (in the order given) to `dst`.
+#[cfg(not(feature = "faster_hex"))]
+pub fn write_strict_hex<D: io::Write>(bytes: &[u8], cap: bool, mut dst: D) -> io::Result<()> {
+    for &b in bytes {
+        byte_to_hex(b, cap, &mut dst)?;
+    }
+    Ok(())
+}
+
+/// Write the trimmed ("compact") hexadecimal representation of `bytes`
+/// (given big-endian), skipping leading zero bytes/nibbles. A value of
+/// all-zero bytes is rendered as the single digit `0`.
+pub fn write_compact_hex<D: io::Write>(bytes: &[u8], cap: bool, mut dst: D) -> io::Result<()> {
+    let mut iter = bytes.iter().skip_while(|b| **b == 0);
+    match iter.next() {
+        None => write_nibble(0, cap, &mut dst),
+        Some(&first) => {
+            if first < 0x10 {
+                write_nibble(first, cap, &mut dst)?;
+            } else {
+                byte_to_hex(first, cap, &mut dst)?;
+            }
+            for &b in iter {
+                byte_to_hex(b, cap, &mut dst)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Parse a (possibly `0x`-prefixed) fixed-width hexadecimal buffer into
+/// exactly `size` bytes, erroring if the digit count doesn't match.
+///
+/// With the `faster_hex` feature enabled, the digits (after the `0x` prefix
+/// is stripped) are decoded with `faster_hex`'s vectorized decoder, falling back to the scalar decoder on malformed input so error
+/// reporting stays consistent with the non-SIMD build.
+#[cfg(feature = "faster_hex")]
+pub fn parse_strict_hex(src: &[u8], size: usize) -> Result<Vec<u8>, ParseHexError> {
+    let src = strip_prefix(src);
+    if src.len() != size * 2 {
+        return Err(ParseHexError::BadSize {
+            expect: size,
+            got: src.len() / 2,
+        });
+    }
+    let mut out = vec![0u8; size];
+    if faster_hex::hex_decode(src, &mut out).is_err() {
+        // fall back to the scalar decoder so the caller gets a precise
+        // `BadDigit` error instead of `faster_hex`'s opaque failure.
+        for (i, pair) in src.chunks(2).enumerate() {
+            out[i] = (hex_digit_to_nibble(pair[0])? << 4) | hex_digit_to_nibble(pair[1])?;
+        }
+    }
+    Ok(out)
+}
+
+/// Parse a (possibly `0x`-prefixed) fixed-width hexadecimal buffer into
+/// exactly `size` bytes, erroring if the digit count doesn't match.
+#[cfg(not(feature = "faster_hex"))]
+pub fn parse_strict_hex(src: &[u8], size: usize) -> Result<Vec<u8>, ParseHexError> {
+    let src = strip_prefix(src);
+    if src.len() != size * 2 {
+        return Err(ParseHexError::BadSize {
+            expect: size,
+            got: src.len() / 2,
+        });
+    }
+    let mut out = Vec::with_capacity(size);
+    for pair in src.chunks(2) {
+        out.push((hex_digit_to_nibble(pair[0])? << 4) | hex_digit_to_nibble(pair[1])?);
+    }
+    Ok(out)
+}
+
+/// Parse a (possibly `0x`-prefixed) compact hexadecimal buffer into a
+/// big-endian byte vector with one byte per pair of digits; leading zero
+/// digits are kept, not trimmed. An odd number of digits is treated as
+/// implicitly zero-padded on the left.
+pub fn parse_compact_hex(src: &[u8]) -> Result<Vec<u8>, ParseHexError> {
+    let src = strip_prefix(src);
+    if src.is_empty() {
+        return Err(ParseHexError::BadSize { expect: 1, got: 0 });
+    }
+    let mut nibbles = Vec::with_capacity(src.len());
+    for &b in src {
+        nibbles.push(hex_digit_to_nibble(b)?);
+    }
+    if nibbles.len() % 2 != 0 {
+        nibbles.insert(0, 0);
+    }
+    Ok(nibbles
+        .chunks(2)
+        .map(|pair| (pair[0] << 4) | pair[1])
+        .collect())
+}
@@ -0,0 +1,61 @@
+//! Test of `SerHex`'s generic `[T; N]` impl across edge-case lengths.
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use stremio_serde_hex::{SerHex, Strict, StrictPfx};
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Empty(#[serde(with = "SerHex::<StrictPfx>")] [u8; 0]);
+
+#[test]
+fn zero_length_array_roundtrips() {
+    let e = Empty([]);
+    let ser = serde_json::to_string(&e).unwrap();
+    assert_eq!(ser, r#""0x""#);
+    let back: Empty = serde_json::from_str(&ser).unwrap();
+    assert_eq!(back, e);
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Big(#[serde(with = "SerHex::<StrictPfx>")] [u8; 128]);
+
+#[test]
+fn over_sixty_four_byte_array_roundtrips() {
+    let mut bytes = [0u8; 128];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let big = Big(bytes);
+    let ser = serde_json::to_string(&big).unwrap();
+    let back: Big = serde_json::from_str(&ser).unwrap();
+    assert_eq!(back, big);
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Nested(#[serde(with = "SerHex::<StrictPfx>")] [[u8; 4]; 3]);
+
+#[test]
+fn nested_array_strips_only_the_outer_prefix() {
+    let nested = Nested([[0xde, 0xad, 0xbe, 0xef], [1, 2, 3, 4], [0xff; 4]]);
+    let ser = serde_json::to_string(&nested).unwrap();
+    assert_eq!(ser, r#""0xdeadbeef01020304ffffffff""#);
+    let back: Nested = serde_json::from_str(&ser).unwrap();
+    assert_eq!(back, nested);
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Hash(#[serde(with = "SerHex::<Strict>")] [u8; 4]);
+
+#[test]
+fn wrong_element_width_is_rejected() {
+    // 6 ascii digits don't divide evenly across the array's 4 elements.
+    let err = serde_json::from_value::<Hash>(json!("abcdef")).unwrap_err();
+    assert_eq!(&err.to_string(), "expected buff size `4` got `3`");
+
+    let err = serde_json::from_value::<Hash>(json!("a")).unwrap_err();
+    assert_eq!(&err.to_string(), "expected buff size `4` got `0`");
+
+    // 4 digits divide evenly into 4 elements, but then each element only
+    // gets 1 digit instead of the 2 (`2 * u8::HEX_SIZE`) it requires.
+    let err = serde_json::from_value::<Hash>(json!("abcd")).unwrap_err();
+    assert_eq!(&err.to_string(), "expected buff size `2` got `1`");
+}
@@ -0,0 +1,47 @@
+//! Test of `SerHex`'s non-human-readable (binary) serialization path, e.g.
+//! via `bincode`.
+use serde::{Deserialize, Serialize};
+use stremio_serde_hex::{SerHex, SerHexOpt, SerHexSeq, StrictPfx};
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Foo {
+    #[serde(with = "SerHex::<StrictPfx>")]
+    bar: [u8; 32],
+    #[serde(with = "SerHex::<StrictPfx>")]
+    bin: u64,
+    #[serde(with = "SerHexOpt::<StrictPfx>")]
+    opt: Option<u8>,
+    #[serde(with = "SerHexSeq::<StrictPfx>")]
+    seq: Vec<u8>,
+}
+
+#[test]
+fn bincode_roundtrip_writes_raw_bytes_not_hex() {
+    let foo = Foo {
+        bar: [0xaa; 32],
+        bin: 0x1234,
+        opt: Some(0xff),
+        seq: vec![0xde, 0xad, 0xbe, 0xef],
+    };
+    let bytes = bincode::serialize(&foo).unwrap();
+    // the `bin` field's 8 raw big-endian bytes must appear verbatim; a hex
+    // round-trip would instead contain the ascii string "0x0000000000001234".
+    assert!(bytes
+        .windows(8)
+        .any(|w| w == [0, 0, 0, 0, 0, 0, 0x12, 0x34]));
+    let back: Foo = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back, foo);
+}
+
+#[test]
+fn bincode_roundtrip_none() {
+    let foo = Foo {
+        bar: [0; 32],
+        bin: 0,
+        opt: None,
+        seq: vec![],
+    };
+    let bytes = bincode::serialize(&foo).unwrap();
+    let back: Foo = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back, foo);
+}
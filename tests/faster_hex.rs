@@ -0,0 +1,30 @@
+//! Test of the `faster_hex` SIMD backend, run via `cargo test --features faster_hex`.
+#![cfg(feature = "faster_hex")]
+
+use serde::{Deserialize, Serialize};
+use stremio_serde_hex::{SerHex, SerHexSeq, StrictCapPfx, StrictPfx};
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Bytes(#[serde(with = "SerHexSeq::<StrictPfx>")] Vec<u8>);
+
+#[test]
+fn faster_hex_roundtrips_a_multi_kilobyte_buffer() {
+    let data: Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+    let bytes = Bytes(data.clone());
+    let ser = serde_json::to_string(&bytes).unwrap();
+    let back: Bytes = serde_json::from_str(&ser).unwrap();
+    assert_eq!(back.0, data);
+}
+
+#[test]
+fn faster_hex_upper_case_matches_scalar_output() {
+    let value = [0xde_u8, 0xad, 0xbe, 0xef];
+    let hex = <[u8; 4] as SerHex<StrictCapPfx>>::into_hex(&value).unwrap();
+    assert_eq!(hex, "0xDEADBEEF");
+}
+
+#[test]
+fn faster_hex_falls_back_to_scalar_on_bad_digit() {
+    let err = <u32 as SerHex<StrictPfx>>::from_hex("0xzzzzzzzz").unwrap_err();
+    assert_eq!(&err.to_string(), "invalid hex digit `z`");
+}
@@ -0,0 +1,27 @@
+//! Test of the `StrictLe`/`StrictLePfx` little-endian byte-order configs.
+use serde::{Deserialize, Serialize};
+use stremio_serde_hex::{SerHex, StrictLe, StrictLePfx};
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Le(#[serde(with = "SerHex::<StrictLe>")] u32);
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct LePfx(#[serde(with = "SerHex::<StrictLePfx>")] u32);
+
+#[test]
+fn little_endian_serializes_bytes_reversed() {
+    let ser = serde_json::to_string(&Le(0x1122_3344)).unwrap();
+    assert_eq!(ser, r#""44332211""#);
+
+    let ser = serde_json::to_string(&LePfx(0x1122_3344)).unwrap();
+    assert_eq!(ser, r#""0x44332211""#);
+}
+
+#[test]
+fn little_endian_roundtrips() {
+    for value in [0u32, 1, 0x1122_3344, u32::MAX] {
+        let ser = serde_json::to_string(&LePfx(value)).unwrap();
+        let LePfx(back) = serde_json::from_str(&ser).unwrap();
+        assert_eq!(back, value);
+    }
+}
@@ -0,0 +1,43 @@
+//! Test of the `Permissive`/`PermissivePfx` configurations' decimal-or-hex
+//! deserialization.
+use serde::{Deserialize, Serialize};
+use stremio_serde_hex::{Permissive, SerHex};
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Num(#[serde(with = "SerHex::<Permissive>")] u32);
+
+#[test]
+fn bare_decimal_digits_parse_as_decimal() {
+    let n: Num = serde_json::from_str(r#""42""#).unwrap();
+    assert_eq!(n, Num(42));
+}
+
+#[test]
+fn zero_x_prefixed_parses_as_hex() {
+    let n: Num = serde_json::from_str(r#""0x2a""#).unwrap();
+    assert_eq!(n, Num(42));
+}
+
+#[test]
+fn bare_hex_digits_parse_as_hex() {
+    // contains `a`, so it isn't all-decimal-digits and is parsed as hex.
+    let n: Num = serde_json::from_str(r#""2a""#).unwrap();
+    assert_eq!(n, Num(42));
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Signed(#[serde(with = "SerHex::<Permissive>")] i32);
+
+#[test]
+fn negative_bare_decimal_parses_as_decimal() {
+    let n: Signed = serde_json::from_str(r#""-42""#).unwrap();
+    assert_eq!(n, Signed(-42));
+}
+
+#[test]
+fn serialization_is_unaffected_by_permissive() {
+    // serialization always stays canonical compact hex, regardless of how
+    // the value was parsed.
+    let ser = serde_json::to_string(&Num(42)).unwrap();
+    assert_eq!(ser, r#""2a""#);
+}
@@ -0,0 +1,85 @@
+//! Test of `SerHex` functionality for signed integers and `i128`/`u128`.
+use serde::{Deserialize, Serialize};
+use stremio_serde_hex::{Compact, CompactPfx, SerHex, Strict};
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Signed {
+    #[serde(with = "SerHex::<CompactPfx>")]
+    compact: i64,
+    #[serde(with = "SerHex::<Strict>")]
+    strict: i32,
+}
+
+#[test]
+fn compact_roundtrip() {
+    for value in [0_i64, -1, 42, -42, i64::MIN, i64::MAX] {
+        let s = Signed {
+            compact: value,
+            strict: 0,
+        };
+        let ser = serde_json::to_string(&s).unwrap();
+        let de: Signed = serde_json::from_str(&ser).unwrap();
+        assert_eq!(de.compact, value);
+    }
+}
+
+#[test]
+fn compact_negative_rendering() {
+    let s = Signed {
+        compact: -42,
+        strict: 0,
+    };
+    let ser = serde_json::to_string(&s).unwrap();
+    assert!(ser.contains(r#""compact":"-0x2a""#));
+}
+
+#[test]
+fn compact_zero_is_not_negative() {
+    let s = Signed {
+        compact: 0,
+        strict: 0,
+    };
+    let ser = serde_json::to_string(&s).unwrap();
+    assert!(ser.contains(r#""compact":"0x0""#));
+}
+
+#[test]
+fn strict_roundtrip_min_and_max() {
+    for value in [i32::MIN, i32::MAX, 0, -1] {
+        let s = Signed {
+            compact: 0,
+            strict: value,
+        };
+        let ser = serde_json::to_string(&s).unwrap();
+        let de: Signed = serde_json::from_str(&ser).unwrap();
+        assert_eq!(de.strict, value);
+    }
+}
+
+#[test]
+fn i128_and_u128_roundtrip() {
+    for value in [0_i128, -1, i128::MIN, i128::MAX] {
+        let hex = <i128 as SerHex<Compact>>::into_hex(&value).unwrap();
+        let back = <i128 as SerHex<Compact>>::from_hex(&hex).unwrap();
+        assert_eq!(back, value);
+    }
+    for value in [0_u128, 1, u128::MAX] {
+        let hex = <u128 as SerHex<Compact>>::into_hex(&value).unwrap();
+        let back = <u128 as SerHex<Compact>>::from_hex(&hex).unwrap();
+        assert_eq!(back, value);
+    }
+}
+
+#[test]
+fn compact_overflow_is_rejected() {
+    // `i8`'s compact magnitude is limited to `i8::MIN.unsigned_abs()` on the
+    // negative side and `i8::MAX` on the positive side; one past either
+    // bound must be rejected rather than silently wrapping.
+    let too_positive = <u8 as SerHex<Compact>>::into_hex(&128_u8).unwrap();
+    let err = <i8 as SerHex<Compact>>::from_hex(&too_positive).unwrap_err();
+    assert!(err.to_string().contains("out of range"));
+
+    let too_negative = format!("-{}", <u8 as SerHex<Compact>>::into_hex(&129_u8).unwrap());
+    let err = <i8 as SerHex<Compact>>::from_hex(&too_negative).unwrap_err();
+    assert!(err.to_string().contains("out of range"));
+}